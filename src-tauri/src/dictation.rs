@@ -0,0 +1,30 @@
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::state::AppState;
+use crate::tray;
+
+const EVENT_DICTATION_STATE_CHANGED: &str = "dictation://state-changed";
+
+/// Flips dictation on/off and returns the new state. This is the single
+/// start/stop path shared by the tray's dictation menu item and the
+/// frontend's hotkey handling, so the tray label/icon stay in sync no
+/// matter which one triggered the change.
+#[tauri::command]
+pub fn toggle_dictation(app: AppHandle) -> bool {
+    let state = app.state::<AppState>();
+    let active = !state.dictation_active();
+    state.set_dictation_active(active);
+
+    let _ = app.emit(EVENT_DICTATION_STATE_CHANGED, active);
+    tray::refresh_dictation_item(&app, active);
+
+    active
+}
+
+/// Called by the frontend once a recognition result comes back, so the
+/// tray's recent-transcriptions submenu and "copy last" item stay current.
+#[tauri::command]
+pub fn record_transcription(app: AppHandle, text: String) {
+    app.state::<AppState>().push_transcription(text);
+    tray::refresh_recent_submenu(&app);
+}
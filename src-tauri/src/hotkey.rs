@@ -0,0 +1,110 @@
+use std::sync::Mutex;
+
+use serde_json::json;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
+
+const SETTINGS_STORE: &str = "settings.json";
+const HOTKEY_KEY: &str = "hotkey";
+const DEFAULT_ACCELERATOR: &str = "CommandOrControl+Shift+D";
+
+const EVENT_HOTKEY_DOWN: &str = "dictation://hotkey-down";
+const EVENT_HOTKEY_UP: &str = "dictation://hotkey-up";
+const EVENT_HOTKEY_ERROR: &str = "dictation://hotkey-error";
+
+/// Tracks the accelerator currently registered with the OS so it can be
+/// unregistered again once the user picks a different binding.
+#[derive(Default)]
+pub struct HotkeyState(Mutex<Option<Shortcut>>);
+
+/// Forwarded from the `tauri_plugin_global_shortcut` handler installed in
+/// `run()`. Only reacts to the accelerator we ourselves registered, since the
+/// plugin dispatches every shortcut through a single global callback.
+pub fn handle_shortcut_event(app: &AppHandle, shortcut: &Shortcut, state: ShortcutState) {
+    let is_ours = app
+        .state::<HotkeyState>()
+        .0
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|current| current == shortcut);
+
+    if !is_ours {
+        return;
+    }
+
+    match state {
+        ShortcutState::Pressed => {
+            let _ = app.emit(EVENT_HOTKEY_DOWN, ());
+        }
+        ShortcutState::Released => {
+            let _ = app.emit(EVENT_HOTKEY_UP, ());
+        }
+    }
+}
+
+/// Reads the persisted accelerator (falling back to the default) and
+/// registers it with the OS. Called once during `setup`. A saved binding can
+/// go stale (e.g. a keyboard-layout change) or the default can already be
+/// owned by another application, so a failure here is surfaced to the
+/// frontend instead of failing `setup` - the user should still be able to
+/// open the window and pick a different binding.
+pub fn restore(app: &AppHandle) {
+    let saved = read_saved_accelerator(app);
+    if let Err(err) = register(app, saved.as_deref().unwrap_or(DEFAULT_ACCELERATOR)) {
+        let _ = app.emit(EVENT_HOTKEY_ERROR, err);
+    }
+}
+
+fn read_saved_accelerator(app: &AppHandle) -> Option<String> {
+    let store = app.store(SETTINGS_STORE).ok()?;
+    store.get(HOTKEY_KEY)?.as_str().map(str::to_string)
+}
+
+fn register(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|_| format!("'{accelerator}' is not a valid shortcut"))?;
+
+    app.global_shortcut()
+        .register(shortcut)
+        .map_err(|e| format!("Could not register '{accelerator}': {e}"))?;
+
+    *app.state::<HotkeyState>().0.lock().unwrap() = Some(shortcut);
+    Ok(())
+}
+
+/// Unregisters the current accelerator (if any) and registers `accelerator`
+/// in its place, persisting the change so it survives a restart. If the new
+/// accelerator can't be registered (e.g. another application already owns
+/// it), the previous binding is restored so the app is never left without a
+/// working hotkey, and the conflict is surfaced back to the caller.
+#[tauri::command]
+pub fn set_hotkey(app: AppHandle, accelerator: String) -> Result<(), String> {
+    let previous = app.state::<HotkeyState>().0.lock().unwrap().take();
+
+    if let Some(previous) = previous {
+        let _ = app.global_shortcut().unregister(previous);
+    }
+
+    if let Err(err) = register(&app, &accelerator) {
+        if let Some(previous) = previous {
+            let _ = app.global_shortcut().register(previous);
+            *app.state::<HotkeyState>().0.lock().unwrap() = Some(previous);
+        }
+        return Err(err);
+    }
+
+    if let Ok(store) = app.store(SETTINGS_STORE) {
+        store.set(HOTKEY_KEY, json!(accelerator));
+        let _ = store.save();
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_hotkey(app: AppHandle) -> String {
+    read_saved_accelerator(&app).unwrap_or_else(|| DEFAULT_ACCELERATOR.to_string())
+}
@@ -1,10 +1,13 @@
-use tauri::{
-    menu::{Menu, MenuItem},
-    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    Manager,
-};
+use tauri::{AppHandle, Manager};
 use tauri_plugin_autostart::MacosLauncher;
 
+mod dictation;
+mod hotkey;
+mod state;
+mod tray;
+
+use state::AppState;
+
 #[cfg(target_os = "macos")]
 use cocoa::appkit::{NSApp, NSApplication, NSApplicationActivationPolicy};
 
@@ -25,72 +28,103 @@ fn set_dock_visible(_visible: bool) {
     // No-op on other platforms - skipTaskbar in config handles Windows
 }
 
+/// Shows or hides the "main" window, keeping the dock icon, tray label and
+/// shared `AppState` flag all in sync regardless of whether this was
+/// triggered by the tray's "show" item or a left-click on the tray icon.
+pub(crate) fn toggle_window_visibility(app: &AppHandle) {
+    if app.state::<AppState>().window_visible() {
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.hide();
+        }
+        set_dock_visible(false);
+        set_window_visible(app, false);
+    } else {
+        show_main_window(app);
+    }
+}
+
+/// Unminimizes, shows and focuses the "main" window, making the dock icon
+/// visible again on macOS. Shared by the tray's left-click/show handlers, the
+/// single-instance callback, and the `RunEvent::Reopen` handler.
+pub(crate) fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        set_dock_visible(true);
+        // On Windows, we need to unminimize first if minimized
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    set_window_visible(app, true);
+}
+
+fn set_window_visible(app: &AppHandle, visible: bool) {
+    app.state::<AppState>().set_window_visible(visible);
+    tray::refresh_show_item(app, visible);
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let mut builder = tauri::Builder::default();
+
+    // Redirect a second launch (e.g. autostart racing a manual start) to the
+    // already-running instance instead of letting it start its own copy.
+    #[cfg(desktop)]
+    {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            show_main_window(app);
+            if argv.iter().any(|arg| arg == "--toggle-dictation") {
+                dictation::toggle_dictation(app.clone());
+            }
+        }));
+    }
+
+    builder
         .plugin(tauri_plugin_opener::init())
-        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    hotkey::handle_shortcut_event(app, shortcut, event.state())
+                })
+                .build(),
+        )
         .plugin(tauri_plugin_autostart::init(
             MacosLauncher::LaunchAgent,
             Some(vec!["--autostart"]), // Pass argument when started via autostart
         ))
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_store::Builder::new().build())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .manage(hotkey::HotkeyState::default())
+        .manage(AppState::default())
+        .invoke_handler(tauri::generate_handler![
+            hotkey::set_hotkey,
+            hotkey::get_hotkey,
+            dictation::toggle_dictation,
+            dictation::record_transcription
+        ])
         .setup(|app| {
+            // Restore the user's configured dictation hotkey (or the default).
+            // A registration failure is reported to the frontend rather than
+            // failing setup, so a stale/conflicting binding can't prevent the
+            // app from starting at all.
+            hotkey::restore(app.handle());
+
             // Check if started via autostart - if so, hide the window and dock icon
             let args: Vec<String> = std::env::args().collect();
             let is_autostart = args.contains(&"--autostart".to_string());
 
+            let window_visible = !is_autostart;
             if is_autostart {
                 if let Some(window) = app.get_webview_window("main") {
                     let _ = window.hide();
                 }
                 set_dock_visible(false);
             }
+            app.state::<AppState>().set_window_visible(window_visible);
 
-            // Create tray menu
-            let show = MenuItem::with_id(app, "show", "Anzeigen", true, None::<&str>)?;
-            let quit = MenuItem::with_id(app, "quit", "Beenden", true, None::<&str>)?;
-            let menu = Menu::with_items(app, &[&show, &quit])?;
-
-            // Create tray icon
-            let _tray = TrayIconBuilder::new()
-                .icon(app.default_window_icon().unwrap().clone())
-                .menu(&menu)
-                .show_menu_on_left_click(false)
-                .on_menu_event(|app, event| match event.id.as_ref() {
-                    "show" => {
-                        if let Some(window) = app.get_webview_window("main") {
-                            set_dock_visible(true);
-                            // On Windows, we need to unminimize first if minimized
-                            let _ = window.unminimize();
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    }
-                    "quit" => {
-                        app.exit(0);
-                    }
-                    _ => {}
-                })
-                .on_tray_icon_event(|tray, event| {
-                    if let TrayIconEvent::Click {
-                        button: MouseButton::Left,
-                        button_state: MouseButtonState::Up,
-                        ..
-                    } = event
-                    {
-                        let app = tray.app_handle();
-                        if let Some(window) = app.get_webview_window("main") {
-                            set_dock_visible(true);
-                            // On Windows, we need to unminimize first if minimized
-                            let _ = window.unminimize();
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    }
-                })
-                .build(app)?;
+            let tray_handles = tray::build(app.handle(), window_visible)?;
+            app.manage(tray_handles);
 
             Ok(())
         })
@@ -99,9 +133,23 @@ pub fn run() {
             if let tauri::WindowEvent::CloseRequested { api, .. } = event {
                 window.hide().unwrap();
                 set_dock_visible(false);
+                set_window_visible(window.app_handle(), false);
                 api.prevent_close();
             }
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // On macOS, clicking the dock icon while the app has no visible
+            // windows doesn't reopen anything by default - do it ourselves.
+            if let tauri::RunEvent::Reopen {
+                has_visible_windows,
+                ..
+            } = event
+            {
+                if !has_visible_windows {
+                    show_main_window(app);
+                }
+            }
+        });
 }
@@ -0,0 +1,82 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// How many recent transcriptions the tray's submenu keeps around for quick
+/// re-insertion.
+pub const MAX_RECENT_TRANSCRIPTIONS: usize = 5;
+
+/// Flags and shared data the tray and frontend both need to stay in sync on,
+/// independent of who last changed them (the hotkey, the tray menu, or a
+/// window event).
+#[derive(Default)]
+pub struct AppState {
+    window_visible: AtomicBool,
+    dictation_active: AtomicBool,
+    recent_transcriptions: Mutex<VecDeque<String>>,
+}
+
+impl AppState {
+    pub fn window_visible(&self) -> bool {
+        self.window_visible.load(Ordering::Relaxed)
+    }
+
+    pub fn set_window_visible(&self, visible: bool) {
+        self.window_visible.store(visible, Ordering::Relaxed);
+    }
+
+    pub fn dictation_active(&self) -> bool {
+        self.dictation_active.load(Ordering::Relaxed)
+    }
+
+    pub fn set_dictation_active(&self, active: bool) {
+        self.dictation_active.store(active, Ordering::Relaxed);
+    }
+
+    pub fn push_transcription(&self, text: String) {
+        let mut recent = self.recent_transcriptions.lock().unwrap();
+        recent.push_front(text);
+        recent.truncate(MAX_RECENT_TRANSCRIPTIONS);
+    }
+
+    pub fn recent_transcriptions(&self) -> Vec<String> {
+        self.recent_transcriptions.lock().unwrap().iter().cloned().collect()
+    }
+
+    pub fn last_transcription(&self) -> Option<String> {
+        self.recent_transcriptions.lock().unwrap().front().cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_transcription_evicts_oldest_beyond_capacity() {
+        let state = AppState::default();
+        for i in 0..MAX_RECENT_TRANSCRIPTIONS + 2 {
+            state.push_transcription(format!("t{i}"));
+        }
+
+        let recent = state.recent_transcriptions();
+        assert_eq!(recent.len(), MAX_RECENT_TRANSCRIPTIONS);
+        assert_eq!(recent.first().unwrap(), &format!("t{}", MAX_RECENT_TRANSCRIPTIONS + 1));
+        assert_eq!(recent.last().unwrap(), "t2");
+    }
+
+    #[test]
+    fn last_transcription_is_the_most_recently_pushed() {
+        let state = AppState::default();
+        state.push_transcription("first".to_string());
+        state.push_transcription("second".to_string());
+
+        assert_eq!(state.last_transcription(), Some("second".to_string()));
+    }
+
+    #[test]
+    fn last_transcription_is_none_when_empty() {
+        let state = AppState::default();
+        assert_eq!(state.last_transcription(), None);
+    }
+}
@@ -0,0 +1,199 @@
+use tauri::{
+    image::Image,
+    menu::{Menu, MenuItem, Submenu},
+    tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Emitter, Manager, Wry,
+};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::dictation;
+use crate::state::{AppState, MAX_RECENT_TRANSCRIPTIONS};
+
+/// Shown in the tray while dictation is active, so it's obvious at a glance
+/// that VoiceTyping is currently listening.
+static RECORDING_ICON: Image = tauri::include_image!("icons/tray-recording.png");
+
+const RECENT_ITEM_PREFIX: &str = "recent_";
+const EVENT_NAVIGATE: &str = "app://navigate";
+const EVENT_INSERT_RECENT: &str = "dictation://insert-recent";
+
+/// Tray/menu item handles that need updating after the menu is built -
+/// gathered here so callers don't have to walk the `Menu` tree to find them.
+pub struct TrayHandles {
+    tray: TrayIcon<Wry>,
+    show_item: MenuItem<Wry>,
+    dictation_item: MenuItem<Wry>,
+    recent_items: Vec<MenuItem<Wry>>,
+}
+
+/// Builds the tray icon and its quick-action menu: show/hide, start/stop
+/// dictation, open settings, copy the last transcription, and a submenu of
+/// recent transcriptions for quick re-insertion.
+pub fn build(app: &AppHandle, window_visible: bool) -> tauri::Result<TrayHandles> {
+    let show_label = if window_visible { "Ausblenden" } else { "Anzeigen" };
+    let show_item = MenuItem::with_id(app, "show", show_label, true, None::<&str>)?;
+    let dictation_item = MenuItem::with_id(app, "toggle_dictation", "Diktat starten", true, None::<&str>)?;
+    let settings_item = MenuItem::with_id(app, "settings", "Einstellungen öffnen", true, None::<&str>)?;
+    let copy_last_item = MenuItem::with_id(app, "copy_last", "Letzte Transkription kopieren", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "quit", "Beenden", true, None::<&str>)?;
+
+    let recent_items = (0..MAX_RECENT_TRANSCRIPTIONS)
+        .map(|i| MenuItem::with_id(app, format!("{RECENT_ITEM_PREFIX}{i}"), "–", false, None::<&str>))
+        .collect::<Result<Vec<_>, _>>()?;
+    let recent_refs: Vec<&MenuItem<Wry>> = recent_items.iter().collect();
+    let recent_submenu = Submenu::with_items(app, "Letzte Transkriptionen", true, &recent_refs)?;
+
+    let menu = Menu::with_items(
+        app,
+        &[
+            &show_item,
+            &dictation_item,
+            &settings_item,
+            &copy_last_item,
+            &recent_submenu,
+            &quit_item,
+        ],
+    )?;
+
+    let tray = TrayIconBuilder::new()
+        .icon(app.default_window_icon().unwrap().clone())
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                crate::show_main_window(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    Ok(TrayHandles {
+        tray,
+        show_item,
+        dictation_item,
+        recent_items,
+    })
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    if let Some(text) = id
+        .strip_prefix(RECENT_ITEM_PREFIX)
+        .and_then(|n| n.parse::<usize>().ok())
+        .and_then(|index| app.state::<AppState>().recent_transcriptions().get(index).cloned())
+    {
+        let _ = app.emit(EVENT_INSERT_RECENT, text);
+        return;
+    }
+
+    match id {
+        "show" => crate::toggle_window_visibility(app),
+        "toggle_dictation" => {
+            dictation::toggle_dictation(app.clone());
+        }
+        "settings" => {
+            crate::show_main_window(app);
+            let _ = app.emit(EVENT_NAVIGATE, "/settings");
+        }
+        "copy_last" => {
+            if let Some(text) = app.state::<AppState>().last_transcription() {
+                let _ = app.clipboard().write_text(text);
+            }
+        }
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+pub fn refresh_show_item(app: &AppHandle, visible: bool) {
+    if let Some(handles) = app.try_state::<TrayHandles>() {
+        let label = if visible { "Ausblenden" } else { "Anzeigen" };
+        let _ = handles.show_item.set_text(label);
+    }
+}
+
+pub fn refresh_dictation_item(app: &AppHandle, active: bool) {
+    if let Some(handles) = app.try_state::<TrayHandles>() {
+        let label = if active { "Diktat stoppen" } else { "Diktat starten" };
+        let _ = handles.dictation_item.set_text(label);
+
+        let icon = if active {
+            RECORDING_ICON.clone()
+        } else {
+            app.default_window_icon().unwrap().clone()
+        };
+        let _ = handles.tray.set_icon(Some(icon));
+    }
+}
+
+/// Refreshes the recent-transcriptions submenu from `AppState`. Slots beyond
+/// the available transcriptions are left in place, disabled, rather than
+/// rebuilding the submenu on every update.
+pub fn refresh_recent_submenu(app: &AppHandle) {
+    let Some(handles) = app.try_state::<TrayHandles>() else {
+        return;
+    };
+    let recent = app.state::<AppState>().recent_transcriptions();
+
+    for (i, item) in handles.recent_items.iter().enumerate() {
+        match recent.get(i) {
+            Some(text) => {
+                let _ = item.set_text(truncate(text));
+                let _ = item.set_enabled(true);
+            }
+            None => {
+                let _ = item.set_text("–");
+                let _ = item.set_enabled(false);
+            }
+        }
+    }
+}
+
+fn truncate(text: &str) -> String {
+    const MAX_LEN: usize = 40;
+    if text.chars().count() <= MAX_LEN {
+        text.to_string()
+    } else {
+        let short: String = text.chars().take(MAX_LEN).collect();
+        format!("{short}…")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_keeps_short_text_unchanged() {
+        let text = "short text";
+        assert_eq!(truncate(text), text);
+    }
+
+    #[test]
+    fn truncate_keeps_exactly_max_len_unchanged() {
+        let text = "a".repeat(40);
+        assert_eq!(truncate(&text), text);
+    }
+
+    #[test]
+    fn truncate_adds_ellipsis_past_max_len() {
+        let text = "a".repeat(41);
+        let result = truncate(&text);
+        assert_eq!(result.chars().count(), 41);
+        assert!(result.ends_with('…'));
+    }
+
+    #[test]
+    fn truncate_is_char_boundary_safe_for_multibyte_text() {
+        // Each "ä" is 2 bytes in UTF-8; naive byte-slicing at the cutoff
+        // would panic instead of landing on a char boundary.
+        let text = "ä".repeat(45);
+        let result = truncate(&text);
+        assert_eq!(result.chars().count(), 41);
+        assert!(result.ends_with('…'));
+    }
+}